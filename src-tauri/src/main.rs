@@ -4,16 +4,30 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::net::TcpStream;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use ssh2::{Channel, Session};
-use tauri::State;
+use ssh2::{
+    CheckResult, Channel, FileStat, HashType, HostKeyType, KnownHostFileKind, KnownHostKeyFormat,
+    Session, Sftp,
+};
+use tauri::{AppHandle, Manager, State};
 use thiserror::Error;
 use uuid::Uuid;
 
+const TERMINAL_READ_CHUNK: usize = 4096;
+const TERMINAL_POLL_INTERVAL: Duration = Duration::from_millis(30);
+const SFTP_CHUNK_SIZE: usize = 32 * 1024;
+const SFTP_PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+// Matches the `session.set_keepalive(true, 30)` interval below; libssh2 only
+// configures the keepalive period, the app still has to call
+// `keepalive_send` itself on that cadence for it to actually go out.
+const JUMP_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Error)]
 enum AppError {
     #[error("I/O error: {0}")]
@@ -24,10 +38,20 @@ enum AppError {
     SessionNotFound,
     #[error("Terminal not found")]
     TerminalNotFound,
+    #[error("Process not found")]
+    ProcessNotFound,
+    #[error("Transfer not found")]
+    TransferNotFound,
+    #[error("Transfer cancelled")]
+    TransferCancelled,
     #[error("Authentication failed")]
     AuthFailed,
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+    #[error("host key does not match the one on record (fingerprint {fingerprint})")]
+    HostKeyMismatch { fingerprint: String },
+    #[error("host key is not in known_hosts yet (fingerprint {fingerprint})")]
+    HostKeyUnknown { fingerprint: String },
 }
 
 impl Serialize for AppError {
@@ -52,6 +76,11 @@ enum AuthMethod {
         private_key_path: String,
         passphrase: Option<String>,
     },
+    #[serde(rename = "agent")]
+    Agent {
+        #[serde(rename = "preferredIdentityComment")]
+        preferred_identity_comment: Option<String>,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -61,6 +90,17 @@ struct ConnectRequest {
     port: u16,
     username: String,
     auth: AuthMethod,
+    /// When `true`, a host key that is not yet in `known_hosts` is trusted
+    /// and recorded automatically instead of failing with `HostKeyUnknown`.
+    #[serde(default)]
+    accept_new: bool,
+    /// Selects the `SshTransport` implementation to connect with. Only
+    /// `"ssh2"` (the default) is currently supported.
+    backend: Option<String>,
+    /// When set, this connection is tunnelled through an already-authenticated
+    /// session to the jump host instead of a direct TCP connection, mirroring
+    /// OpenSSH's `ProxyJump`.
+    jump: Option<Box<ConnectRequest>>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -99,27 +139,207 @@ struct SftpEntry {
     modified_at: Option<u64>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SftpTransferStartResult {
+    transfer_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SftpProgressEvent {
+    transfer_id: String,
+    path: String,
+    bytes_done: u64,
+    bytes_total: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SftpCompleteEvent {
+    transfer_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SftpErrorEvent {
+    transfer_id: String,
+    message: String,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct TerminalStartResult {
     terminal_id: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalOutputEvent {
+    terminal_id: String,
+    chunk: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalClosedEvent {
+    terminal_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessSpawnResult {
+    process_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessOutputEvent {
+    process_id: String,
+    chunk: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProcessExitEvent {
+    process_id: String,
+    exit_status: i32,
+}
+
+/// Operations the rest of the app needs from an SSH transport, kept narrow
+/// enough that a second backend (e.g. libssh) could implement it without
+/// touching any command handler.
+trait SshTransport: Send {
+    fn authenticated(&self) -> bool;
+    fn set_blocking(&self, blocking: bool);
+    fn keepalive_send(&mut self) -> AppResult<u32>;
+    fn sftp(&self) -> AppResult<Sftp>;
+    fn exec_channel(&self, command: &str) -> AppResult<Channel>;
+    fn shell_channel(&self, cols: u32, rows: u32) -> AppResult<Channel>;
+    /// Opens a direct-tcpip channel through this session, used to tunnel a
+    /// ProxyJump hop's traffic without ever exposing the underlying ssh2
+    /// session to the connect path.
+    fn open_tunnel(&self, host: &str, port: u16) -> AppResult<Channel>;
+}
+
+enum SshBackend {
+    Ssh2(Session),
+    // future: Libssh(...)
+}
+
+impl SshTransport for SshBackend {
+    fn authenticated(&self) -> bool {
+        match self {
+            SshBackend::Ssh2(session) => session.authenticated(),
+        }
+    }
+
+    fn set_blocking(&self, blocking: bool) {
+        match self {
+            SshBackend::Ssh2(session) => session.set_blocking(blocking),
+        }
+    }
+
+    fn keepalive_send(&mut self) -> AppResult<u32> {
+        match self {
+            SshBackend::Ssh2(session) => Ok(session.keepalive_send()?),
+        }
+    }
+
+    fn sftp(&self) -> AppResult<Sftp> {
+        match self {
+            SshBackend::Ssh2(session) => Ok(session.sftp()?),
+        }
+    }
+
+    fn exec_channel(&self, command: &str) -> AppResult<Channel> {
+        match self {
+            SshBackend::Ssh2(session) => {
+                let mut channel = session.channel_session()?;
+                channel.exec(command)?;
+                Ok(channel)
+            }
+        }
+    }
+
+    fn shell_channel(&self, cols: u32, rows: u32) -> AppResult<Channel> {
+        match self {
+            SshBackend::Ssh2(session) => {
+                let mut channel = session.channel_session()?;
+                let dimensions = Some((cols.max(20), rows.max(5), 0, 0));
+                channel.request_pty("xterm-256color", None, dimensions)?;
+                channel.shell()?;
+                Ok(channel)
+            }
+        }
+    }
+
+    fn open_tunnel(&self, host: &str, port: u16) -> AppResult<Channel> {
+        match self {
+            SshBackend::Ssh2(session) => Ok(session.channel_direct_tcpip(host, port, None)?),
+        }
+    }
+}
+
+/// ssh2's blocking flag lives on the whole `Session`, not the channel, so a
+/// streaming reader can't just flip it on once — that would starve any
+/// other command sharing the session (`run_command`, SFTP transfers, ...)
+/// with spurious `WouldBlock` errors. This guard flips it to non-blocking
+/// only for the instant a poll is in flight and restores blocking mode on
+/// drop, and callers take it while holding the session's own lock so the
+/// toggle and the read it guards are never observed by another command.
+struct NonBlockingGuard<'a> {
+    backend: &'a SshBackend,
+}
+
+impl<'a> NonBlockingGuard<'a> {
+    fn new(backend: &'a SshBackend) -> Self {
+        backend.set_blocking(false);
+        Self { backend }
+    }
+}
+
+impl Drop for NonBlockingGuard<'_> {
+    fn drop(&mut self) {
+        self.backend.set_blocking(true);
+    }
+}
+
 struct SshSession {
     info: SessionInfo,
-    session: Session,
-    _tcp: TcpStream,
+    session: SshBackend,
+    // `None` when the connection was tunnelled through `jump` instead of a
+    // direct TCP connection.
+    _tcp: Option<TcpStream>,
+    // Kept alive for the lifetime of the connection so the direct-tcpip
+    // channel it opened for us keeps working; torn down (via `Drop`) when
+    // this session is removed in `close_session`. Wrapped in its own
+    // `Arc<Mutex<_>>` (rather than `Box`) so the background keepalive pump
+    // spawned in `connect_ssh` can reach it without owning it outright.
+    jump: Option<Arc<Mutex<SshSession>>>,
 }
 
 struct TerminalSession {
     session_id: String,
-    channel: Channel,
+    channel: Arc<Mutex<Channel>>,
+    stop: Arc<AtomicBool>,
+}
+
+struct ProcessSession {
+    session_id: String,
+    channel: Arc<Mutex<Channel>>,
+    stop: Arc<AtomicBool>,
 }
 
 #[derive(Clone, Default)]
 struct AppState {
-    sessions: Arc<Mutex<HashMap<String, SshSession>>>,
+    // Each session is wrapped in its own `Arc<Mutex<_>>` so a long-running
+    // operation on one session (e.g. an SFTP transfer) only locks that
+    // session, not the whole map other commands look sessions up in.
+    sessions: Arc<Mutex<HashMap<String, Arc<Mutex<SshSession>>>>>,
     terminals: Arc<Mutex<HashMap<String, TerminalSession>>>,
+    processes: Arc<Mutex<HashMap<String, ProcessSession>>>,
+    transfers: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
 }
 
 fn now_utc() -> DateTime<Utc> {
@@ -130,6 +350,184 @@ fn set_last_active(session: &mut SshSession) {
     session.info.last_active_at = now_utc();
 }
 
+fn get_session_arc(state: &AppState, session_id: &str) -> AppResult<Arc<Mutex<SshSession>>> {
+    let sessions = state
+        .sessions
+        .lock()
+        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+    sessions
+        .get(session_id)
+        .cloned()
+        .ok_or(AppError::SessionNotFound)
+}
+
+/// Locks the session only for as long as `op` needs its `Sftp` handle, then
+/// releases it. Callers doing a multi-file transfer call this once per file
+/// instead of holding the session for the whole walk, so an interactive
+/// terminal/process or another command on the same session isn't frozen out
+/// for the transfer's entire duration.
+fn with_session_sftp<T>(
+    state: &AppState,
+    session_id: &str,
+    op: impl FnOnce(&Sftp) -> AppResult<T>,
+) -> AppResult<T> {
+    let session_arc = get_session_arc(state, session_id)?;
+    let mut item = session_arc
+        .lock()
+        .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
+    let sftp = item.session.sftp()?;
+    let result = op(&sftp)?;
+    set_last_active(&mut item);
+    Ok(result)
+}
+
+fn known_hosts_path() -> AppResult<PathBuf> {
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or_else(|| AppError::InvalidInput("could not determine home directory".to_string()))?;
+    Ok(home.join(".ssh").join("known_hosts"))
+}
+
+fn host_key_fingerprint(session: &Session) -> String {
+    session
+        .host_key_hash(HashType::Sha256)
+        .map(|hash| {
+            hash.iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<_>>()
+                .join(":")
+        })
+        .unwrap_or_default()
+}
+
+// The key format recorded with a known_hosts entry follows the presented
+// key's type; the file itself is always written in OpenSSH format.
+fn known_host_format(key_type: HostKeyType) -> KnownHostKeyFormat {
+    key_type.into()
+}
+
+// `check_port` hashes/matches non-standard ports under the `[host]:port`
+// token, so entries we persist via `add` (which only takes a bare host
+// string) must be encoded the same way or they can never match on the next
+// connection.
+fn known_host_token(host: &str, port: u16) -> String {
+    if port == 22 {
+        host.to_string()
+    } else {
+        format!("[{}]:{}", host, port)
+    }
+}
+
+fn verify_host_key(session: &Session, host: &str, port: u16, accept_new: bool) -> AppResult<()> {
+    let (key, key_type) = session
+        .host_key()
+        .ok_or_else(|| AppError::InvalidInput("server did not present a host key".to_string()))?;
+
+    let mut known_hosts = session.known_hosts()?;
+    let path = known_hosts_path()?;
+    if path.exists() {
+        known_hosts.read_file(&path, KnownHostFileKind::OpenSSH)?;
+    }
+
+    match known_hosts.check_port(host, port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => Err(AppError::HostKeyMismatch {
+            fingerprint: host_key_fingerprint(session),
+        }),
+        CheckResult::NotFound => {
+            if accept_new {
+                known_hosts.add(
+                    &known_host_token(host, port),
+                    key,
+                    "",
+                    known_host_format(key_type),
+                )?;
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                known_hosts.write_file(&path, KnownHostFileKind::OpenSSH)?;
+                Ok(())
+            } else {
+                Err(AppError::HostKeyUnknown {
+                    fingerprint: host_key_fingerprint(session),
+                })
+            }
+        }
+        CheckResult::Failure => Err(AppError::InvalidInput(
+            "failed to check host key against known_hosts".to_string(),
+        )),
+    }
+}
+
+fn authenticate_with_agent(
+    session: &Session,
+    username: &str,
+    preferred_identity_comment: Option<&str>,
+) -> AppResult<()> {
+    let mut agent = session.agent()?;
+    agent.connect()?;
+    agent.list_identities()?;
+
+    let identities = agent.identities()?;
+    let candidates: Vec<_> = match preferred_identity_comment {
+        Some(comment) => identities
+            .iter()
+            .filter(|identity| identity.comment() == comment)
+            .collect(),
+        None => identities.iter().collect(),
+    };
+
+    let mut authenticated = false;
+    for identity in candidates {
+        if agent.userauth(username, identity).is_ok() {
+            authenticated = true;
+            break;
+        }
+    }
+
+    // Disconnect is best-effort teardown; a failure here must not mask a
+    // userauth that already succeeded above.
+    let _ = agent.disconnect();
+
+    if authenticated {
+        Ok(())
+    } else {
+        Err(AppError::AuthFailed)
+    }
+}
+
+fn select_backend(session: Session, backend: Option<&str>) -> AppResult<SshBackend> {
+    match backend {
+        None | Some("ssh2") => Ok(SshBackend::Ssh2(session)),
+        Some(other) => Err(AppError::InvalidInput(format!(
+            "unsupported ssh backend: {other}"
+        ))),
+    }
+}
+
+/// ProxyJump hops aren't registered in `AppState` and so never get the
+/// frontend's periodic `send_keepalive` calls, even though their tunnel
+/// carries all of the target session's traffic. Pumps `keepalive_send` on
+/// `jump` for as long as it stays alive; holds only a `Weak` reference so
+/// the pump can't keep the jump session (or the tunnel riding on it) around
+/// past `close_session` tearing down the session that owns it.
+fn spawn_jump_keepalive_pump(jump: &Arc<Mutex<SshSession>>) {
+    let jump = Arc::downgrade(jump);
+    std::thread::spawn(move || loop {
+        std::thread::sleep(JUMP_KEEPALIVE_INTERVAL);
+        let Some(jump) = jump.upgrade() else {
+            break;
+        };
+        let Ok(mut jump) = jump.lock() else {
+            break;
+        };
+        if jump.session.keepalive_send().is_err() {
+            break;
+        }
+    });
+}
+
 fn connect_ssh(request: ConnectRequest) -> AppResult<SshSession> {
     if request.host.trim().is_empty() || request.username.trim().is_empty() {
         return Err(AppError::InvalidInput(
@@ -137,13 +535,40 @@ fn connect_ssh(request: ConnectRequest) -> AppResult<SshSession> {
         ));
     }
 
-    let address = format!("{}:{}", request.host.trim(), request.port);
-    let tcp = TcpStream::connect(address)?;
-    tcp.set_nodelay(true)?;
+    let host = request.host.trim().to_string();
+    let port = request.port;
+
+    let jump = match request.jump {
+        Some(jump_request) => Some(Arc::new(Mutex::new(connect_ssh(*jump_request)?))),
+        None => None,
+    };
 
     let mut session = Session::new()?;
-    session.set_tcp_stream(tcp.try_clone()?);
+    let tcp = match &jump {
+        Some(jump_session) => {
+            let jump_guard = jump_session
+                .lock()
+                .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
+            let tunnel = jump_guard.session.open_tunnel(&host, port)?;
+            // The jump session itself is never used for anything but
+            // carrying this tunnel, so it's safe (and necessary, since
+            // ssh2's blocking flag is per-session) to leave it non-blocking
+            // for the tunnel's lifetime rather than toggling it per read.
+            jump_guard.session.set_blocking(false);
+            drop(jump_guard);
+            session.set_tcp_stream(tunnel);
+            spawn_jump_keepalive_pump(jump_session);
+            None
+        }
+        None => {
+            let tcp = TcpStream::connect(format!("{host}:{port}"))?;
+            tcp.set_nodelay(true)?;
+            session.set_tcp_stream(tcp.try_clone()?);
+            Some(tcp)
+        }
+    };
     session.handshake()?;
+    verify_host_key(&session, &host, port, request.accept_new)?;
 
     match request.auth {
         AuthMethod::Password { password } => {
@@ -160,6 +585,15 @@ fn connect_ssh(request: ConnectRequest) -> AppResult<SshSession> {
                 passphrase.as_deref(),
             )?;
         }
+        AuthMethod::Agent {
+            preferred_identity_comment,
+        } => {
+            authenticate_with_agent(
+                &session,
+                request.username.trim(),
+                preferred_identity_comment.as_deref(),
+            )?;
+        }
     }
 
     if !session.authenticated() {
@@ -168,6 +602,8 @@ fn connect_ssh(request: ConnectRequest) -> AppResult<SshSession> {
 
     session.set_keepalive(true, 30);
 
+    let backend = select_backend(session, request.backend.as_deref())?;
+
     let id = Uuid::new_v4().to_string();
     let connected_at = now_utc();
     let label = request
@@ -185,11 +621,50 @@ fn connect_ssh(request: ConnectRequest) -> AppResult<SshSession> {
             connected_at,
             last_active_at: connected_at,
         },
-        session,
+        session: backend,
         _tcp: tcp,
+        jump,
     })
 }
 
+#[tauri::command]
+fn trust_host_key(host: String, port: u16) -> AppResult<()> {
+    let trimmed_host = host.trim();
+    if trimmed_host.is_empty() {
+        return Err(AppError::InvalidInput("host is required".to_string()));
+    }
+
+    let tcp = TcpStream::connect(format!("{}:{}", trimmed_host, port))?;
+    tcp.set_nodelay(true)?;
+
+    let mut session = Session::new()?;
+    session.set_tcp_stream(tcp);
+    session.handshake()?;
+
+    let (key, key_type) = session
+        .host_key()
+        .ok_or_else(|| AppError::InvalidInput("server did not present a host key".to_string()))?;
+
+    let mut known_hosts = session.known_hosts()?;
+    let path = known_hosts_path()?;
+    if path.exists() {
+        known_hosts.read_file(&path, KnownHostFileKind::OpenSSH)?;
+    }
+
+    known_hosts.add(
+        &known_host_token(trimmed_host, port),
+        key,
+        "",
+        known_host_format(key_type),
+    )?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    known_hosts.write_file(&path, KnownHostFileKind::OpenSSH)?;
+
+    Ok(())
+}
+
 #[tauri::command]
 fn create_session(state: State<'_, AppState>, request: ConnectRequest) -> AppResult<SessionInfo> {
     let created = connect_ssh(request)?;
@@ -199,7 +674,7 @@ fn create_session(state: State<'_, AppState>, request: ConnectRequest) -> AppRes
         .sessions
         .lock()
         .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    sessions.insert(session_info.id.clone(), created);
+    sessions.insert(session_info.id.clone(), Arc::new(Mutex::new(created)));
 
     Ok(session_info)
 }
@@ -211,7 +686,10 @@ fn list_sessions(state: State<'_, AppState>) -> AppResult<Vec<SessionInfo>> {
         .lock()
         .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
 
-    let mut list: Vec<SessionInfo> = sessions.values().map(|item| item.info.clone()).collect();
+    let mut list: Vec<SessionInfo> = sessions
+        .values()
+        .filter_map(|item| item.lock().ok().map(|item| item.info.clone()))
+        .collect();
     list.sort_by(|a, b| b.connected_at.cmp(&a.connected_at));
     Ok(list)
 }
@@ -242,9 +720,34 @@ fn close_session(state: State<'_, AppState>, session_id: String) -> AppResult<()
         .collect();
 
     for terminal_id in keys {
-        if let Some(mut terminal) = terminals.remove(&terminal_id) {
-            let _ = terminal.channel.close();
-            let _ = terminal.channel.wait_close();
+        if let Some(terminal) = terminals.remove(&terminal_id) {
+            terminal.stop.store(true, Ordering::SeqCst);
+            if let Ok(mut channel) = terminal.channel.lock() {
+                let _ = channel.close();
+                let _ = channel.wait_close();
+            }
+        }
+    }
+
+    let mut processes = state
+        .processes
+        .lock()
+        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+
+    let process_keys: Vec<String> = processes
+        .iter()
+        .filter(|(_, process)| process.session_id == session_id)
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    for process_id in process_keys {
+        if let Some(process) = processes.remove(&process_id) {
+            process.stop.store(true, Ordering::SeqCst);
+            if let Ok(mut channel) = process.channel.lock() {
+                let _ = channel.send_eof();
+                let _ = channel.close();
+                let _ = channel.wait_close();
+            }
         }
     }
 
@@ -263,16 +766,12 @@ fn run_command(
         ));
     }
 
-    let mut sessions = state
-        .sessions
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let mut item = session_arc
         .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
+        .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
 
-    let mut channel = item.session.channel_session()?;
-    channel.exec(command.as_str())?;
+    let mut channel = item.session.exec_channel(command.as_str())?;
 
     let mut stdout = String::new();
     channel.read_to_string(&mut stdout)?;
@@ -283,7 +782,7 @@ fn run_command(
     channel.wait_close()?;
     let exit_code = channel.exit_status()?;
 
-    set_last_active(item);
+    set_last_active(&mut item);
 
     Ok(CommandOutput {
         stdout,
@@ -294,21 +793,50 @@ fn run_command(
 
 #[tauri::command]
 fn send_keepalive(state: State<'_, AppState>, session_id: String) -> AppResult<KeepaliveStatus> {
-    let mut sessions = state
-        .sessions
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let mut item = session_arc
         .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
+        .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
 
     let seconds_to_next = item.session.keepalive_send()?;
-    set_last_active(item);
+    set_last_active(&mut item);
 
     Ok(KeepaliveStatus { seconds_to_next })
 }
 
+fn sftp_entry_from_stat(path: &Path, stat: &FileStat) -> SftpEntry {
+    let name = path
+        .file_name()
+        .and_then(|x| x.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let permissions = stat.perm;
+    let kind = permissions
+        .map(|perm| match perm & 0o170000 {
+            0o040000 => "dir",
+            0o100000 => "file",
+            0o120000 => "symlink",
+            _ => "unknown",
+        })
+        .unwrap_or("unknown")
+        .to_string();
+
+    SftpEntry {
+        name,
+        path: path.to_string_lossy().to_string(),
+        kind,
+        size: stat.size,
+        permissions,
+        modified_at: stat.mtime,
+    }
+}
+
+fn stat_is_dir(stat: &FileStat) -> bool {
+    stat.perm
+        .map(|perm| perm & 0o170000 == 0o040000)
+        .unwrap_or(false)
+}
+
 #[tauri::command]
 fn sftp_list_dir(
     state: State<'_, AppState>,
@@ -321,152 +849,483 @@ fn sftp_list_dir(
         path.trim()
     };
 
-    let mut sessions = state
-        .sessions
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let mut item = session_arc
         .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
+        .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
 
     let sftp = item.session.sftp()?;
     let entries = sftp.readdir(Path::new(normalized))?;
 
     let mapped = entries
-        .into_iter()
-        .map(|(path_buf, stat)| {
-            let name = path_buf
+        .iter()
+        .map(|(path_buf, stat)| sftp_entry_from_stat(path_buf, stat))
+        .collect();
+
+    set_last_active(&mut item);
+
+    Ok(mapped)
+}
+
+#[tauri::command]
+fn sftp_stat(state: State<'_, AppState>, session_id: String, path: String) -> AppResult<SftpEntry> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err(AppError::InvalidInput("path is required".to_string()));
+    }
+
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let mut item = session_arc
+        .lock()
+        .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
+
+    let sftp = item.session.sftp()?;
+    let path_buf = Path::new(trimmed);
+    let stat = sftp.lstat(path_buf)?;
+    let entry = sftp_entry_from_stat(path_buf, &stat);
+
+    set_last_active(&mut item);
+
+    Ok(entry)
+}
+
+fn emit_sftp_progress(
+    app_handle: &AppHandle,
+    transfer_id: &str,
+    path: &str,
+    bytes_done: u64,
+    bytes_total: u64,
+) {
+    let _ = app_handle.emit_all(
+        "sftp://progress",
+        SftpProgressEvent {
+            transfer_id: transfer_id.to_string(),
+            path: path.to_string(),
+            bytes_done,
+            bytes_total,
+        },
+    );
+}
+
+fn copy_with_progress<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    cancel: &AtomicBool,
+    app_handle: &AppHandle,
+    transfer_id: &str,
+    path: &str,
+    bytes_total: u64,
+) -> AppResult<()> {
+    let mut buf = [0_u8; SFTP_CHUNK_SIZE];
+    let mut bytes_done: u64 = 0;
+    let mut last_emit = Instant::now();
+
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(AppError::TransferCancelled);
+        }
+
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        writer.write_all(&buf[..n])?;
+        bytes_done += n as u64;
+
+        if last_emit.elapsed() >= SFTP_PROGRESS_INTERVAL {
+            emit_sftp_progress(app_handle, transfer_id, path, bytes_done, bytes_total);
+            last_emit = Instant::now();
+        }
+    }
+
+    writer.flush()?;
+    emit_sftp_progress(app_handle, transfer_id, path, bytes_done, bytes_total);
+
+    Ok(())
+}
+
+/// Remote paths are POSIX regardless of the client's platform, so children
+/// are joined with an explicit `/` rather than `Path::join`, which would use
+/// `\` on Windows and send a path the server can't parse.
+fn remote_child(remote_path: &Path, name: &std::ffi::OsStr) -> PathBuf {
+    let base = remote_path.to_string_lossy();
+    let name = name.to_string_lossy();
+    if base.ends_with('/') {
+        PathBuf::from(format!("{base}{name}"))
+    } else {
+        PathBuf::from(format!("{base}/{name}"))
+    }
+}
+
+fn upload_recursive(
+    state: &AppState,
+    session_id: &str,
+    local_path: &Path,
+    remote_path: &Path,
+    cancel: &AtomicBool,
+    app_handle: &AppHandle,
+    transfer_id: &str,
+) -> AppResult<()> {
+    if cancel.load(Ordering::SeqCst) {
+        return Err(AppError::TransferCancelled);
+    }
+
+    let metadata = std::fs::metadata(local_path)?;
+
+    if metadata.is_dir() {
+        with_session_sftp(state, session_id, |sftp| {
+            if !matches!(sftp.stat(remote_path), Ok(stat) if stat_is_dir(&stat)) {
+                sftp.mkdir(remote_path, 0o755)?;
+            }
+            Ok(())
+        })?;
+
+        for entry in std::fs::read_dir(local_path)? {
+            let entry = entry?;
+            upload_recursive(
+                state,
+                session_id,
+                &entry.path(),
+                &remote_child(remote_path, &entry.file_name()),
+                cancel,
+                app_handle,
+                transfer_id,
+            )?;
+        }
+
+        Ok(())
+    } else {
+        let mut local_file = File::open(local_path)?;
+        with_session_sftp(state, session_id, |sftp| {
+            let mut remote_file = sftp.create(remote_path)?;
+            copy_with_progress(
+                &mut local_file,
+                &mut remote_file,
+                cancel,
+                app_handle,
+                transfer_id,
+                &remote_path.to_string_lossy(),
+                metadata.len(),
+            )
+        })
+    }
+}
+
+fn download_recursive(
+    state: &AppState,
+    session_id: &str,
+    remote_path: &Path,
+    local_path: &Path,
+    cancel: &AtomicBool,
+    app_handle: &AppHandle,
+    transfer_id: &str,
+) -> AppResult<()> {
+    if cancel.load(Ordering::SeqCst) {
+        return Err(AppError::TransferCancelled);
+    }
+
+    let stat = with_session_sftp(state, session_id, |sftp| Ok(sftp.lstat(remote_path)?))?;
+
+    if stat_is_dir(&stat) {
+        std::fs::create_dir_all(local_path)?;
+
+        let children = with_session_sftp(state, session_id, |sftp| Ok(sftp.readdir(remote_path)?))?;
+
+        for (child_remote, child_stat) in children {
+            let name = child_remote
                 .file_name()
                 .and_then(|x| x.to_str())
-                .unwrap_or_default()
-                .to_string();
-            let path = path_buf.to_string_lossy().to_string();
-            let permissions = stat.perm;
-            let kind = permissions
-                .map(|perm| match perm & 0o170000 {
-                    0o040000 => "dir",
-                    0o100000 => "file",
-                    0o120000 => "symlink",
-                    _ => "unknown",
-                })
-                .unwrap_or("unknown")
-                .to_string();
-
-            SftpEntry {
-                name,
-                path,
-                kind,
-                size: stat.size,
-                permissions,
-                modified_at: stat.mtime,
+                .unwrap_or_default();
+            let child_local = local_path.join(name);
+
+            if stat_is_dir(&child_stat) {
+                download_recursive(
+                    state,
+                    session_id,
+                    &child_remote,
+                    &child_local,
+                    cancel,
+                    app_handle,
+                    transfer_id,
+                )?;
+            } else {
+                let mut local_file = File::create(&child_local)?;
+                with_session_sftp(state, session_id, |sftp| {
+                    let mut remote_file = sftp.open(&child_remote)?;
+                    copy_with_progress(
+                        &mut remote_file,
+                        &mut local_file,
+                        cancel,
+                        app_handle,
+                        transfer_id,
+                        &child_remote.to_string_lossy(),
+                        child_stat.size.unwrap_or(0),
+                    )
+                })?;
             }
+        }
+
+        Ok(())
+    } else {
+        let mut local_file = File::create(local_path)?;
+        with_session_sftp(state, session_id, |sftp| {
+            let mut remote_file = sftp.open(remote_path)?;
+            copy_with_progress(
+                &mut remote_file,
+                &mut local_file,
+                cancel,
+                app_handle,
+                transfer_id,
+                &remote_path.to_string_lossy(),
+                stat.size.unwrap_or(0),
+            )
         })
-        .collect();
+    }
+}
 
-    set_last_active(item);
+fn finish_transfer(app_handle: &AppHandle, state: &AppState, transfer_id: &str, result: AppResult<()>) {
+    if let Ok(mut transfers) = state.transfers.lock() {
+        transfers.remove(transfer_id);
+    }
 
-    Ok(mapped)
+    match result {
+        Ok(()) => {
+            let _ = app_handle.emit_all(
+                "sftp://complete",
+                SftpCompleteEvent {
+                    transfer_id: transfer_id.to_string(),
+                },
+            );
+        }
+        Err(err) => {
+            let _ = app_handle.emit_all(
+                "sftp://error",
+                SftpErrorEvent {
+                    transfer_id: transfer_id.to_string(),
+                    message: err.to_string(),
+                },
+            );
+        }
+    }
+}
+
+fn start_sftp_transfer<F>(state: &State<'_, AppState>, app_handle: AppHandle, transfer: F) -> AppResult<SftpTransferStartResult>
+where
+    F: FnOnce(&AppState, &AtomicBool, &AppHandle, &str) -> AppResult<()> + Send + 'static,
+{
+    let transfer_id = Uuid::new_v4().to_string();
+    let cancel = Arc::new(AtomicBool::new(false));
+
+    {
+        let mut transfers = state
+            .transfers
+            .lock()
+            .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+        transfers.insert(transfer_id.clone(), cancel.clone());
+    }
+
+    let state = state.inner().clone();
+    let spawned_transfer_id = transfer_id.clone();
+
+    std::thread::spawn(move || {
+        let result = transfer(&state, &cancel, &app_handle, &spawned_transfer_id);
+        finish_transfer(&app_handle, &state, &spawned_transfer_id, result);
+    });
+
+    Ok(SftpTransferStartResult { transfer_id })
 }
 
 #[tauri::command]
 fn sftp_upload(
     state: State<'_, AppState>,
+    app_handle: AppHandle,
     session_id: String,
     local_path: String,
     remote_path: String,
-) -> AppResult<()> {
+) -> AppResult<SftpTransferStartResult> {
     if local_path.trim().is_empty() || remote_path.trim().is_empty() {
         return Err(AppError::InvalidInput(
             "local_path and remote_path are required".to_string(),
         ));
     }
 
-    let mut sessions = state
-        .sessions
-        .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
-
-    let mut local_file = File::open(local_path.trim())?;
-    let sftp = item.session.sftp()?;
-    let mut remote_file = sftp.create(Path::new(remote_path.trim()))?;
-    std::io::copy(&mut local_file, &mut remote_file)?;
-    remote_file.flush()?;
-
-    set_last_active(item);
+    let local_path = PathBuf::from(local_path.trim());
+    let remote_path = PathBuf::from(remote_path.trim());
 
-    Ok(())
+    start_sftp_transfer(&state, app_handle, move |state, cancel, app_handle, transfer_id| {
+        upload_recursive(state, &session_id, &local_path, &remote_path, cancel, app_handle, transfer_id)
+    })
 }
 
 #[tauri::command]
 fn sftp_download(
     state: State<'_, AppState>,
+    app_handle: AppHandle,
     session_id: String,
     remote_path: String,
     local_path: String,
-) -> AppResult<()> {
+) -> AppResult<SftpTransferStartResult> {
     if local_path.trim().is_empty() || remote_path.trim().is_empty() {
         return Err(AppError::InvalidInput(
             "local_path and remote_path are required".to_string(),
         ));
     }
 
-    let mut sessions = state
-        .sessions
+    let local_path = PathBuf::from(local_path.trim());
+    let remote_path = PathBuf::from(remote_path.trim());
+
+    start_sftp_transfer(&state, app_handle, move |state, cancel, app_handle, transfer_id| {
+        download_recursive(state, &session_id, &remote_path, &local_path, cancel, app_handle, transfer_id)
+    })
+}
+
+#[tauri::command]
+fn sftp_cancel(state: State<'_, AppState>, transfer_id: String) -> AppResult<()> {
+    let transfers = state
+        .transfers
         .lock()
         .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
+    let cancel = transfers
+        .get(&transfer_id)
+        .ok_or(AppError::TransferNotFound)?;
+    cancel.store(true, Ordering::SeqCst);
+    Ok(())
+}
 
-    let sftp = item.session.sftp()?;
-    let mut remote_file = sftp.open(Path::new(remote_path.trim()))?;
-    let mut local_file = File::create(local_path.trim())?;
-    std::io::copy(&mut remote_file, &mut local_file)?;
-    local_file.flush()?;
+fn emit_terminal_output(app_handle: &AppHandle, terminal_id: &str, data: &[u8]) {
+    let _ = app_handle.emit_all(
+        "terminal://output",
+        TerminalOutputEvent {
+            terminal_id: terminal_id.to_string(),
+            chunk: String::from_utf8_lossy(data).to_string(),
+        },
+    );
+}
 
-    set_last_active(item);
+fn spawn_terminal_reader(
+    app_handle: AppHandle,
+    state: AppState,
+    session_id: String,
+    terminal_id: String,
+    channel: Arc<Mutex<Channel>>,
+    stop: Arc<AtomicBool>,
+) {
+    std::thread::spawn(move || {
+        let mut buf = [0_u8; TERMINAL_READ_CHUNK];
+
+        'reader: loop {
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
 
-    Ok(())
+            let mut read_any = false;
+
+            {
+                let session_arc = {
+                    let sessions = match state.sessions.lock() {
+                        Ok(sessions) => sessions,
+                        Err(_) => break,
+                    };
+                    match sessions.get(&session_id) {
+                        Some(session_arc) => session_arc.clone(),
+                        None => break,
+                    }
+                };
+                let session = match session_arc.lock() {
+                    Ok(session) => session,
+                    Err(_) => break,
+                };
+                let _non_blocking = NonBlockingGuard::new(&session.session);
+
+                let mut channel = match channel.lock() {
+                    Ok(channel) => channel,
+                    Err(_) => break,
+                };
+
+                match channel.read(&mut buf) {
+                    Ok(0) => break 'reader,
+                    Ok(n) => {
+                        read_any = true;
+                        emit_terminal_output(&app_handle, &terminal_id, &buf[..n]);
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+                    Err(_) => break 'reader,
+                }
+
+                match channel.stderr().read(&mut buf) {
+                    Ok(0) => {}
+                    Ok(n) => {
+                        read_any = true;
+                        emit_terminal_output(&app_handle, &terminal_id, &buf[..n]);
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+                    Err(_) => break 'reader,
+                }
+            }
+
+            if !read_any {
+                std::thread::sleep(TERMINAL_POLL_INTERVAL);
+            }
+        }
+
+        if let Ok(mut terminals) = state.terminals.lock() {
+            terminals.remove(&terminal_id);
+        }
+
+        let _ = app_handle.emit_all(
+            "terminal://closed",
+            TerminalClosedEvent {
+                terminal_id: terminal_id.clone(),
+            },
+        );
+    });
 }
 
 #[tauri::command]
 fn start_terminal(
     state: State<'_, AppState>,
+    app_handle: AppHandle,
     session_id: String,
     cols: u32,
     rows: u32,
 ) -> AppResult<TerminalStartResult> {
-    let mut sessions = state
-        .sessions
-        .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let item = sessions
-        .get_mut(&session_id)
-        .ok_or(AppError::SessionNotFound)?;
-
-    let mut channel = item.session.channel_session()?;
-    let dimensions = Some((cols.max(20), rows.max(5), 0, 0));
-    channel.request_pty("xterm-256color", None, dimensions)?;
-    channel.shell()?;
-    item.session.set_blocking(false);
-    set_last_active(item);
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let channel = {
+        let mut item = session_arc
+            .lock()
+            .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
+        let channel = item.session.shell_channel(cols, rows)?;
+        set_last_active(&mut item);
+        channel
+    };
 
     let terminal_id = Uuid::new_v4().to_string();
-    drop(sessions);
+
+    let channel = Arc::new(Mutex::new(channel));
+    let stop = Arc::new(AtomicBool::new(false));
 
     let terminal = TerminalSession {
-        session_id,
-        channel,
+        session_id: session_id.clone(),
+        channel: channel.clone(),
+        stop: stop.clone(),
     };
 
-    let mut terminals = state
-        .terminals
-        .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    terminals.insert(terminal_id.clone(), terminal);
+    {
+        let mut terminals = state
+            .terminals
+            .lock()
+            .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+        terminals.insert(terminal_id.clone(), terminal);
+    }
+
+    spawn_terminal_reader(
+        app_handle,
+        state.inner().clone(),
+        session_id,
+        terminal_id.clone(),
+        channel,
+        stop,
+    );
 
     Ok(TerminalStartResult { terminal_id })
 }
@@ -474,72 +1333,36 @@ fn start_terminal(
 #[tauri::command]
 fn terminal_write(state: State<'_, AppState>, terminal_id: String, data: String) -> AppResult<()> {
     let session_id = {
-        let mut terminals = state
+        let terminals = state
             .terminals
             .lock()
             .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
         let terminal = terminals
-            .get_mut(&terminal_id)
+            .get(&terminal_id)
             .ok_or(AppError::TerminalNotFound)?;
-        terminal.channel.write_all(data.as_bytes())?;
-        terminal.channel.flush()?;
+        let mut channel = terminal
+            .channel
+            .lock()
+            .map_err(|_| AppError::InvalidInput("terminal lock poisoned".to_string()))?;
+        channel.write_all(data.as_bytes())?;
+        channel.flush()?;
         terminal.session_id.clone()
     };
 
-    let mut sessions = state
-        .sessions
-        .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    if let Some(item) = sessions.get_mut(&session_id) {
-        set_last_active(item);
-    }
-
-    Ok(())
-}
-
-#[tauri::command]
-fn terminal_read(state: State<'_, AppState>, terminal_id: String) -> AppResult<String> {
-    let session_id;
-    let mut output = Vec::<u8>::new();
-    {
-        let mut terminals = state
-            .terminals
+    let session_arc = {
+        let sessions = state
+            .sessions
             .lock()
             .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-        let terminal = terminals
-            .get_mut(&terminal_id)
-            .ok_or(AppError::TerminalNotFound)?;
-        session_id = terminal.session_id.clone();
-
-        let mut buf = [0_u8; 4096];
-        loop {
-            match terminal.channel.read(&mut buf) {
-                Ok(0) => break,
-                Ok(n) => output.extend_from_slice(&buf[..n]),
-                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => break,
-                Err(err) => return Err(AppError::Io(err)),
-            }
-        }
-
-        loop {
-            match terminal.channel.stderr().read(&mut buf) {
-                Ok(0) => break,
-                Ok(n) => output.extend_from_slice(&buf[..n]),
-                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => break,
-                Err(err) => return Err(AppError::Io(err)),
-            }
+        sessions.get(&session_id).cloned()
+    };
+    if let Some(session_arc) = session_arc {
+        if let Ok(mut item) = session_arc.lock() {
+            set_last_active(&mut item);
         }
     }
 
-    let mut sessions = state
-        .sessions
-        .lock()
-        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    if let Some(item) = sessions.get_mut(&session_id) {
-        set_last_active(item);
-    }
-
-    Ok(String::from_utf8_lossy(&output).to_string())
+    Ok(())
 }
 
 #[tauri::command]
@@ -549,16 +1372,18 @@ fn terminal_resize(
     cols: u32,
     rows: u32,
 ) -> AppResult<()> {
-    let mut terminals = state
+    let terminals = state
         .terminals
         .lock()
         .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
     let terminal = terminals
-        .get_mut(&terminal_id)
+        .get(&terminal_id)
         .ok_or(AppError::TerminalNotFound)?;
-    terminal
+    let mut channel = terminal
         .channel
-        .request_pty_size(cols.max(20), rows.max(5), None, None)?;
+        .lock()
+        .map_err(|_| AppError::InvalidInput("terminal lock poisoned".to_string()))?;
+    channel.request_pty_size(cols.max(20), rows.max(5), None, None)?;
     Ok(())
 }
 
@@ -568,11 +1393,219 @@ fn close_terminal(state: State<'_, AppState>, terminal_id: String) -> AppResult<
         .terminals
         .lock()
         .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
-    let mut terminal = terminals
+    let terminal = terminals
         .remove(&terminal_id)
         .ok_or(AppError::TerminalNotFound)?;
-    let _ = terminal.channel.close();
-    let _ = terminal.channel.wait_close();
+    terminal.stop.store(true, Ordering::SeqCst);
+    let mut channel = terminal
+        .channel
+        .lock()
+        .map_err(|_| AppError::InvalidInput("terminal lock poisoned".to_string()))?;
+    let _ = channel.close();
+    let _ = channel.wait_close();
+    Ok(())
+}
+
+fn emit_process_chunk(app_handle: &AppHandle, event: &str, process_id: &str, data: &[u8]) {
+    let _ = app_handle.emit_all(
+        event,
+        ProcessOutputEvent {
+            process_id: process_id.to_string(),
+            chunk: String::from_utf8_lossy(data).to_string(),
+        },
+    );
+}
+
+fn spawn_process_reader(
+    app_handle: AppHandle,
+    state: AppState,
+    session_id: String,
+    process_id: String,
+    channel: Arc<Mutex<Channel>>,
+    stop: Arc<AtomicBool>,
+) {
+    std::thread::spawn(move || {
+        let mut buf = [0_u8; TERMINAL_READ_CHUNK];
+
+        'reader: loop {
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let mut read_any = false;
+            let mut at_eof = false;
+
+            {
+                let session_arc = {
+                    let sessions = match state.sessions.lock() {
+                        Ok(sessions) => sessions,
+                        Err(_) => break,
+                    };
+                    match sessions.get(&session_id) {
+                        Some(session_arc) => session_arc.clone(),
+                        None => break,
+                    }
+                };
+                let session = match session_arc.lock() {
+                    Ok(session) => session,
+                    Err(_) => break,
+                };
+                let _non_blocking = NonBlockingGuard::new(&session.session);
+
+                let mut channel = match channel.lock() {
+                    Ok(channel) => channel,
+                    Err(_) => break,
+                };
+
+                match channel.read(&mut buf) {
+                    Ok(0) => {}
+                    Ok(n) => {
+                        read_any = true;
+                        emit_process_chunk(&app_handle, "process://stdout", &process_id, &buf[..n]);
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+                    Err(_) => break 'reader,
+                }
+
+                match channel.stderr().read(&mut buf) {
+                    Ok(0) => {}
+                    Ok(n) => {
+                        read_any = true;
+                        emit_process_chunk(&app_handle, "process://stderr", &process_id, &buf[..n]);
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+                    Err(_) => break 'reader,
+                }
+
+                at_eof = channel.eof();
+            }
+
+            if !read_any {
+                if at_eof {
+                    break 'reader;
+                }
+                std::thread::sleep(TERMINAL_POLL_INTERVAL);
+            }
+        }
+
+        let exit_status = {
+            let mut channel = channel.lock().ok();
+            channel
+                .as_mut()
+                .and_then(|channel| {
+                    let _ = channel.wait_close();
+                    channel.exit_status().ok()
+                })
+                .unwrap_or(-1)
+        };
+
+        if let Ok(mut processes) = state.processes.lock() {
+            processes.remove(&process_id);
+        }
+
+        let _ = app_handle.emit_all(
+            "process://exit",
+            ProcessExitEvent {
+                process_id: process_id.clone(),
+                exit_status,
+            },
+        );
+    });
+}
+
+#[tauri::command]
+fn spawn_process(
+    state: State<'_, AppState>,
+    app_handle: AppHandle,
+    session_id: String,
+    command: String,
+) -> AppResult<ProcessSpawnResult> {
+    if command.trim().is_empty() {
+        return Err(AppError::InvalidInput(
+            "command cannot be empty".to_string(),
+        ));
+    }
+
+    let session_arc = get_session_arc(&state, &session_id)?;
+    let channel = {
+        let mut item = session_arc
+            .lock()
+            .map_err(|_| AppError::InvalidInput("session lock poisoned".to_string()))?;
+        let channel = item.session.exec_channel(command.trim())?;
+        set_last_active(&mut item);
+        channel
+    };
+
+    let process_id = Uuid::new_v4().to_string();
+
+    let channel = Arc::new(Mutex::new(channel));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let process = ProcessSession {
+        session_id: session_id.clone(),
+        channel: channel.clone(),
+        stop: stop.clone(),
+    };
+
+    {
+        let mut processes = state
+            .processes
+            .lock()
+            .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+        processes.insert(process_id.clone(), process);
+    }
+
+    spawn_process_reader(
+        app_handle,
+        state.inner().clone(),
+        session_id,
+        process_id.clone(),
+        channel,
+        stop,
+    );
+
+    Ok(ProcessSpawnResult { process_id })
+}
+
+#[tauri::command]
+fn process_write_stdin(
+    state: State<'_, AppState>,
+    process_id: String,
+    data: String,
+) -> AppResult<()> {
+    let processes = state
+        .processes
+        .lock()
+        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+    let process = processes
+        .get(&process_id)
+        .ok_or(AppError::ProcessNotFound)?;
+    let mut channel = process
+        .channel
+        .lock()
+        .map_err(|_| AppError::InvalidInput("process lock poisoned".to_string()))?;
+    channel.write_all(data.as_bytes())?;
+    channel.flush()?;
+    Ok(())
+}
+
+#[tauri::command]
+fn process_kill(state: State<'_, AppState>, process_id: String) -> AppResult<()> {
+    let mut processes = state
+        .processes
+        .lock()
+        .map_err(|_| AppError::InvalidInput("state lock poisoned".to_string()))?;
+    let process = processes
+        .remove(&process_id)
+        .ok_or(AppError::ProcessNotFound)?;
+    process.stop.store(true, Ordering::SeqCst);
+    let mut channel = process
+        .channel
+        .lock()
+        .map_err(|_| AppError::InvalidInput("process lock poisoned".to_string()))?;
+    let _ = channel.send_eof();
+    let _ = channel.close();
+    let _ = channel.wait_close();
     Ok(())
 }
 
@@ -581,18 +1614,23 @@ fn main() {
         .manage(AppState::default())
         .invoke_handler(tauri::generate_handler![
             create_session,
+            trust_host_key,
             list_sessions,
             close_session,
             run_command,
             send_keepalive,
             sftp_list_dir,
+            sftp_stat,
             sftp_upload,
             sftp_download,
+            sftp_cancel,
             start_terminal,
             terminal_write,
-            terminal_read,
             terminal_resize,
-            close_terminal
+            close_terminal,
+            spawn_process,
+            process_write_stdin,
+            process_kill
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri app");